@@ -13,8 +13,8 @@ if (!(Test-Path -Path .\release)) {
     }
 }
 
-# Build and copy all servers
-Get-ChildItem -Directory | Where-Object { $_.Name -ne "release" } | ForEach-Object {
+# Build and copy all servers (redact is a shared library, built as a dependency)
+Get-ChildItem -Directory | Where-Object { $_.Name -ne "release" -and $_.Name -ne "redact" } | ForEach-Object {
     $folderName = $_.Name
     Set-Location -Path ".\$folderName"
     
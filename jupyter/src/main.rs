@@ -6,6 +6,7 @@ use log::{info, warn};
 use std::fs;
 use std::path::Path;
 use std::process::Command;
+use mcpx_redact::Redactor;
 
 // Request structure for notebook path and cell content
 #[derive(Debug, Deserialize, schemars::JsonSchema)]
@@ -45,11 +46,13 @@ struct Cell {
 
 // Main JupyterTools implementation
 #[derive(Debug, Clone)]
-pub struct JupyterTools {}
+pub struct JupyterTools {
+    redactor: Redactor,
+}
 
 impl JupyterTools {
-    pub fn new() -> Self {
-        JupyterTools {}
+    pub fn new(redactor: Redactor) -> Self {
+        JupyterTools { redactor }
     }
 
     fn read_notebook(&self, path: &str) -> Option<Notebook> {
@@ -127,16 +130,17 @@ impl JupyterTools {
         
         let mut results = Vec::new();
         
+        // Mask secrets before the output is saved to the notebook or returned
         if !output.stdout.is_empty() {
             if let Ok(stdout) = String::from_utf8(output.stdout) {
-                results.push(stdout);
+                results.push(self.redactor.redact(&stdout));
             }
         }
         
         if !output.stderr.is_empty() {
             if let Ok(stderr) = String::from_utf8(output.stderr) {
                 if !stderr.is_empty() {
-                    results.push(format!("Error: {}", stderr));
+                    results.push(format!("Error: {}", self.redactor.redact(&stderr)));
                 }
             }
         }
@@ -316,7 +320,7 @@ impl JupyterTools {
         }
         
         match fs::read_to_string(&request.notebook_path) {
-            Ok(content) => format!("Successfully read notebook. Content:\n{}", content),
+            Ok(content) => format!("Successfully read notebook. Content:\n{}", self.redactor.redact(&content)),
             Err(e) => format!("Error reading notebook: {}", e),
         }
     }
@@ -357,8 +361,11 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     
     info!("Starting Jupyter MCP Server (File Mode)");
     
+    // Parse command line arguments for redaction options
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    
     // Create our tools
-    let tools = JupyterTools::new();
+    let tools = JupyterTools::new(Redactor::from_args(&args)?);
     
     // Set up the transport for stdio communication
     let transport = (stdin(), stdout());
@@ -19,7 +19,7 @@ async fn main() -> Result<()> {
     let args: Vec<String> = std::env::args().skip(1).collect();
     
     // Initialize the PowerShell service
-    let service = powershell::PowerShellService::new(&args);
+    let service = powershell::PowerShellService::new(&args)?;
 
     // Use stdin/stdout as the transport mechanism
     let transport = (stdin(), stdout());
@@ -4,6 +4,8 @@ use rmcp::{model::ServerInfo, ServerHandler, tool};
 use std::sync::Arc;
 use uuid::Uuid;
 
+use mcpx_redact::Redactor;
+
 use crate::tools;
 
 /// Main service for PowerShell command execution
@@ -11,11 +13,12 @@ use crate::tools;
 pub struct PowerShellService {
     pub allowed_commands: Vec<String>,
     pub restricted_mode: bool,
+    pub redactor: Redactor,
     pub running_processes: Arc<DashMap<String, tools::process::PowerShellProcess>>,
 }
 
 impl PowerShellService {
-    pub fn new(args: &[String]) -> Self {
+    pub fn new(args: &[String]) -> Result<Self> {
         // Parse arguments
         let mut restricted_mode = false;
         let mut allowed_commands = Vec::new();
@@ -29,11 +32,12 @@ impl PowerShellService {
             }
         }
 
-        Self {
+        Ok(Self {
             allowed_commands,
             restricted_mode,
+            redactor: Redactor::from_args(args)?,
             running_processes: Arc::new(DashMap::new()),
-        }
+        })
     }
 
     /// Check if a command is allowed to run in restricted mode
@@ -60,10 +64,10 @@ impl PowerShellService {
     #[tool(description = "Execute a PowerShell command and wait for it to complete. Returns the complete output of the command including standard output and error streams.")]
     async fn execute_command(&self, #[tool(param)] command: String) -> String {
         if !self.is_command_allowed(&command) {
-            return format!("Error: Command '{}' is not allowed in restricted mode", command);
+            return format!("Error: Command '{}' is not allowed in restricted mode", self.redactor.redact(&command));
         }
 
-        match tools::execute::execute_command(command).await {
+        match tools::execute::execute_command(self, command).await {
             Ok(output) => output,
             Err(e) => format!("Error executing PowerShell command: {}", e),
         }
@@ -73,7 +77,7 @@ impl PowerShellService {
     #[tool(description = "Start a PowerShell command as a background process. Returns a process ID that can be used to check status or retrieve output later.")]
     async fn start_background_process(&self, #[tool(param)] command: String) -> String {
         if !self.is_command_allowed(&command) {
-            return format!("Error: Command '{}' is not allowed in restricted mode", command);
+            return format!("Error: Command '{}' is not allowed in restricted mode", self.redactor.redact(&command));
         }
 
         match tools::process::start_background_process(self, command).await {
@@ -116,12 +120,12 @@ impl PowerShellService {
         if self.restricted_mode {
             for cmd in &commands {
                 if !self.is_command_allowed(cmd) {
-                    return format!("Error: Command '{}' is not allowed in restricted mode", cmd);
+                    return format!("Error: Command '{}' is not allowed in restricted mode", self.redactor.redact(cmd));
                 }
             }
         }
 
-        match tools::execute::execute_command_sequence(commands).await {
+        match tools::execute::execute_command_sequence(self, commands).await {
             Ok(output) => output,
             Err(e) => format!("Error executing command sequence: {}", e),
         }
@@ -144,7 +148,7 @@ impl PowerShellService {
             return format!("Error: Script execution is not allowed in restricted mode");
         }
 
-        match tools::execute::execute_script_file(script_path).await {
+        match tools::execute::execute_script_file(self, script_path).await {
             Ok(output) => output,
             Err(e) => format!("Error executing script file: {}", e),
         }
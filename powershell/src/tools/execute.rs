@@ -6,6 +6,8 @@ use std::process::Stdio;
 use tokio::process::Command;
 use tokio::io::{AsyncReadExt, BufReader};
 
+use crate::powershell::PowerShellService;
+
 #[derive(Debug, Serialize, Deserialize, JsonSchema)]
 pub struct CommandOutput {
     pub stdout: String,
@@ -15,7 +17,7 @@ pub struct CommandOutput {
 }
 
 /// Execute a PowerShell command synchronously and capture its output
-pub async fn execute_command(command: String) -> Result<String> {
+pub async fn execute_command(service: &PowerShellService, command: String) -> Result<String> {
     // Create a PowerShell process with the command
     let mut cmd = Command::new("powershell.exe");
     cmd.arg("-NoProfile")
@@ -26,7 +28,7 @@ pub async fn execute_command(command: String) -> Result<String> {
        .stdout(Stdio::piped())
        .stderr(Stdio::piped());
 
-    log::info!("Executing PowerShell command: {}", command);
+    log::info!("Executing PowerShell command: {}", service.redactor.redact(&command));
     
     // Execute the command and capture output
     let mut child = cmd.spawn()?;
@@ -48,10 +50,10 @@ pub async fn execute_command(command: String) -> Result<String> {
     // Wait for the process to complete and get the exit status
     let status = child.wait().await?;
     
-    // Prepare the output
+    // Prepare the output, masking any secrets before it reaches the client
     let output = CommandOutput {
-        stdout: String::from_utf8_lossy(&stdout).to_string(),
-        stderr: String::from_utf8_lossy(&stderr).to_string(),
+        stdout: service.redactor.redact(&String::from_utf8_lossy(&stdout)),
+        stderr: service.redactor.redact(&String::from_utf8_lossy(&stderr)),
         exit_code: status.code(),
         success: status.success(),
     };
@@ -61,7 +63,7 @@ pub async fn execute_command(command: String) -> Result<String> {
 }
 
 /// Execute a sequence of PowerShell commands in a single session
-pub async fn execute_command_sequence(commands: Vec<String>) -> Result<String> {
+pub async fn execute_command_sequence(service: &PowerShellService, commands: Vec<String>) -> Result<String> {
     if commands.is_empty() {
         return Err(anyhow!("No commands provided to execute"));
     }
@@ -70,11 +72,11 @@ pub async fn execute_command_sequence(commands: Vec<String>) -> Result<String> {
     let combined_command = commands.join("; ");
     
     // Execute the combined command
-    execute_command(combined_command).await
+    execute_command(service, combined_command).await
 }
 
 /// Execute a PowerShell script file
-pub async fn execute_script_file(script_path: String) -> Result<String> {
+pub async fn execute_script_file(service: &PowerShellService, script_path: String) -> Result<String> {
     // Validate that the file exists and has .ps1 extension
     let path = Path::new(&script_path);
     if !path.exists() {
@@ -117,10 +119,10 @@ pub async fn execute_script_file(script_path: String) -> Result<String> {
     // Wait for the process to complete and get the exit status
     let status = child.wait().await?;
     
-    // Prepare the output
+    // Prepare the output, masking any secrets before it reaches the client
     let output = CommandOutput {
-        stdout: String::from_utf8_lossy(&stdout).to_string(),
-        stderr: String::from_utf8_lossy(&stderr).to_string(),
+        stdout: service.redactor.redact(&String::from_utf8_lossy(&stdout)),
+        stderr: service.redactor.redact(&String::from_utf8_lossy(&stderr)),
         exit_code: status.code(),
         success: status.success(),
     };
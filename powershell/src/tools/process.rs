@@ -55,7 +55,7 @@ pub async fn start_background_process(service: &PowerShellService, command: Stri
        .stdout(Stdio::piped())
        .stderr(Stdio::piped());
 
-    log::info!("Starting background PowerShell process: {}", command);
+    log::info!("Starting background PowerShell process: {}", service.redactor.redact(&command));
     
     // Start the process
     let mut child = cmd.spawn()?;
@@ -190,7 +190,7 @@ pub async fn get_process_status(service: &PowerShellService, process_id: &str) -
         
         let status = ProcessStatus {
             process_id: ps_process.process_id.clone(),
-            command: ps_process.command.clone(),
+            command: service.redactor.redact(&ps_process.command),
             running: is_running,
             exit_code,
             start_time: ps_process.start_time.to_rfc3339(),
@@ -252,8 +252,8 @@ pub async fn get_process_output(service: &PowerShellService, process_id: &str) -
         
         let output = ProcessOutput {
             process_id: ps_process.process_id.clone(),
-            stdout: String::from_utf8_lossy(&stdout_buffer).to_string(),
-            stderr: String::from_utf8_lossy(&stderr_buffer).to_string(),
+            stdout: service.redactor.redact(&String::from_utf8_lossy(&stdout_buffer)),
+            stderr: service.redactor.redact(&String::from_utf8_lossy(&stderr_buffer)),
             exit_code,
             completed: !is_running,
         };
@@ -275,7 +275,7 @@ pub async fn list_running_processes(service: &PowerShellService) -> Result<Strin
         
         let status = ProcessStatus {
             process_id: ps_process.process_id.clone(),
-            command: ps_process.command.clone(),
+            command: service.redactor.redact(&ps_process.command),
             running: is_running,
             exit_code,
             start_time: ps_process.start_time.to_rfc3339(),